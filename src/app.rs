@@ -1,28 +1,139 @@
 // src/app.rs
 
+use crate::config::Config;
 use crate::crawler;
+use crate::watcher;
 use eframe::egui;
 use egui_extras::Column;
 use egui_extras::TableBuilder;
 use itertools::Itertools;
+use rayon::ThreadPool;
+use std::collections::HashMap;
+use std::collections::HashSet;
 use std::path::PathBuf;
 use std::sync::mpsc;
 use std::sync::mpsc::Receiver;
 use std::sync::mpsc::Sender;
+use std::sync::Arc;
+use std::time::SystemTime;
 use tokio::runtime::Handle;
 
 // We'll do a small chunked read so you see directories appear live!
 pub enum AppMessage {
     Subdir(PathBuf),
+    /// A Rust build-artifact directory (a `target/` next to a `Cargo.toml`)
+    /// was found, along with its reclaimable size and newest mtime.
+    CleanTarget {
+        path: PathBuf,
+        size_bytes: u64,
+        last_modified: SystemTime,
+    },
+    /// A target directory was successfully sent to the OS trash.
+    Trashed {
+        path: PathBuf,
+        size_bytes: u64,
+    },
+    /// A previously trashed directory was restored.
+    Restored {
+        path: PathBuf,
+        size_bytes: u64,
+    },
+    /// A trash or restore operation failed.
+    TrashFailed {
+        path: PathBuf,
+        error: String,
+    },
+    /// A watched directory (or detected target) was removed.
+    Removed(PathBuf),
+    /// A watched target directory's size changed.
+    Updated {
+        path: PathBuf,
+        size_bytes: u64,
+    },
+    /// Progress update for a "Clean selected" batch: `completed` out of
+    /// `total` targets have been processed so far.
+    CleanProgress {
+        completed: usize,
+        total: usize,
+    },
+    /// A "Clean selected" batch finished.
+    CleanComplete {
+        total_freed: u64,
+        count: usize,
+    },
     Done,
 }
 
+/// A detected Cargo `target/` directory, ready to be shown (and eventually
+/// cleaned) in the Targets column.
+pub struct CleanTarget {
+    pub path: PathBuf,
+    pub size_bytes: u64,
+    pub last_modified: SystemTime,
+}
+
+/// A directory we've sent to the OS trash this session, kept around so the
+/// user can undo a mistaken delete with "Restore last".
+struct TrashedEntry {
+    path: PathBuf,
+    size_bytes: u64,
+}
+
+/// How many trashed directories we remember for "Restore last".
+const MAX_RECENTLY_TRASHED: usize = 10;
+
+/// Width of the draggable handle rendered between resizable table columns.
+const SPLITTER_WIDTH: f32 = 6.0;
+
+/// Minimum width a resizable table column can be dragged down to.
+const MIN_COLUMN_WIDTH: f32 = 150.0;
+
+/// Formats a byte count as a human-readable size using KiB/MiB/GiB units.
+fn human_size(bytes: u64) -> String {
+    const KIB: f64 = 1024.0;
+    const MIB: f64 = KIB * 1024.0;
+    const GIB: f64 = MIB * 1024.0;
+
+    let bytes = bytes as f64;
+    if bytes >= GIB {
+        format!("{:.2} GiB", bytes / GIB)
+    } else if bytes >= MIB {
+        format!("{:.2} MiB", bytes / MIB)
+    } else if bytes >= KIB {
+        format!("{:.2} KiB", bytes / KIB)
+    } else {
+        format!("{bytes:.0} B")
+    }
+}
+
+/// Formats how long ago `modified` was, e.g. "3d old" or "2h old".
+fn format_age(modified: SystemTime) -> String {
+    let age = match SystemTime::now().duration_since(modified) {
+        Ok(age) => age,
+        Err(_) => return "just now".to_owned(),
+    };
+
+    let secs = age.as_secs();
+    if secs < 60 {
+        format!("{secs}s old")
+    } else if secs < 3600 {
+        format!("{}m old", secs / 60)
+    } else if secs < 86_400 {
+        format!("{}h old", secs / 3600)
+    } else {
+        format!("{}d old", secs / 86_400)
+    }
+}
+
 pub struct MyApp {
     root_dirs: Vec<PathBuf>,
     search_text: String,
     subdirs: Vec<PathBuf>,
     search_results: Option<(String, Vec<PathBuf>)>,
 
+    // Detected Cargo build-artifact directories, ready to be cleaned:
+    targets: Vec<CleanTarget>,
+
     // For incremental indexing:
     tx: Sender<AppMessage>,
     rx: Receiver<AppMessage>,
@@ -31,46 +142,136 @@ pub struct MyApp {
     // We'll hold a handle to the runtime so we can spawn tasks.
     rt_handle: Handle,
 
+    new_root_input: String,
+
     // New for ignore patterns:
     ignore_patterns: Vec<String>,
     new_pattern_input: String,
+    /// Set when the pattern in `new_pattern_input` failed to compile as a
+    /// glob, so the user sees *why* instead of the pattern silently
+    /// vanishing.
+    pattern_error: Option<String>,
+
+    // For trashing/restoring detected targets:
+    recently_trashed: Vec<TrashedEntry>,
+    trash_status: Option<String>,
+
+    // For live filesystem watching, keyed by root. A root is watched iff
+    // it has an entry here; dropping the `RootWatcher` stops the watch.
+    watchers: HashMap<PathBuf, watcher::RootWatcher>,
+    // Worker pool shared by every watcher's re-measure of a changed target/
+    // dir, built lazily on the first watch and reused after, rather than
+    // spinning up a fresh pool (sized to all available cores) per root.
+    watch_pool: Option<Arc<ThreadPool>>,
+
+    // Window size and column widths, kept in sync so they can be persisted.
+    window_width: f32,
+    window_height: f32,
+    column_widths: [f32; 4],
+
+    // Worker threads used to crawl roots and subtrees in parallel.
+    thread_count: usize,
+
+    // For multi-select and batch cleaning of detected targets:
+    selected: HashSet<PathBuf>,
+    select_older_than_days: u32,
+    select_larger_than_mib: u64,
+    show_clean_confirm: bool,
+    clean_progress: Option<(usize, usize)>,
+    clean_summary: Option<String>,
 }
 
 impl MyApp {
-    pub fn new(rt_handle: Handle) -> Self {
+    pub fn new(rt_handle: Handle, config: Config) -> Self {
         let (tx, rx) = mpsc::channel();
 
         Self {
-            root_dirs: vec!["D:\\Repos".into(), "G:\\ml".into(), "G:\\Repos".into()],
+            root_dirs: config.root_dirs,
             subdirs: (1..2000)
                 .map(|i| PathBuf::from(format!("Subdir {} - {}", i, "asd".repeat(45))))
                 .collect(),
             search_text: String::new(),
             search_results: None,
+            targets: Vec::new(),
+            new_root_input: String::new(),
             tx,
             rx,
             indexing_in_progress: false,
             rt_handle,
-            ignore_patterns: vec![".git".to_owned()],
+            ignore_patterns: config.ignore_patterns,
             new_pattern_input: String::new(),
+            pattern_error: None,
+            recently_trashed: Vec::new(),
+            trash_status: None,
+            watchers: HashMap::new(),
+            watch_pool: None,
+            window_width: config.window_width,
+            window_height: config.window_height,
+            column_widths: config.column_widths,
+            thread_count: std::thread::available_parallelism()
+                .map(std::num::NonZeroUsize::get)
+                .unwrap_or(1),
+            selected: HashSet::new(),
+            select_older_than_days: 30,
+            select_larger_than_mib: 100,
+            show_clean_confirm: false,
+            clean_progress: None,
+            clean_summary: None,
+        }
+    }
+
+    /// Builds a [`Config`] from the app's current state, for persisting.
+    fn to_config(&self) -> Config {
+        Config {
+            root_dirs: self.root_dirs.clone(),
+            ignore_patterns: self.ignore_patterns.clone(),
+            window_width: self.window_width,
+            window_height: self.window_height,
+            column_widths: self.column_widths,
+        }
+    }
+
+    /// Persists the current config, logging (but not panicking on) failure.
+    fn save_config(&self) {
+        if let Err(err) = self.to_config().save() {
+            tracing::warn!("Failed to save config: {}", err);
         }
     }
 
     /// Called when user clicks "Refresh subdirs"
     fn refresh_subdirs(&mut self) {
         self.subdirs.clear();
+        self.targets.clear();
         self.search_results = None;
         self.indexing_in_progress = true;
 
-        // Copy current ignore patterns into local variable for the background thread
-        let ignore_list = self.ignore_patterns.clone();
+        // Compile the ignore patterns once for the whole crawl. Patterns added
+        // through the UI are validated as they're added, but ones loaded from
+        // the config file are not, so a compile error is reported here
+        // rather than unwrapped across threads.
+        let ignore_matcher = match crawler::build_ignore_matcher(&self.ignore_patterns) {
+            Ok(matcher) => Arc::new(matcher),
+            Err(err) => {
+                self.pattern_error = Some(format!("Failed to compile ignore patterns: {err}"));
+                self.indexing_in_progress = false;
+                return;
+            }
+        };
+
+        let pool = match crawler::build_worker_pool(Some(self.thread_count)) {
+            Ok(pool) => pool,
+            Err(err) => {
+                self.trash_status = Some(format!("Failed to start worker pool: {err}"));
+                self.indexing_in_progress = false;
+                return;
+            }
+        };
+
         let tx_clone = self.tx.clone();
         let root_dirs = self.root_dirs.clone();
         self.rt_handle.spawn(async move {
             tokio::task::spawn_blocking(move || {
-                for root in root_dirs {
-                    crawler::gather_descendant_dirs_streaming(root, &tx_clone, &ignore_list);
-                }
+                crawler::crawl_roots_parallel(root_dirs, tx_clone.clone(), ignore_matcher, pool);
                 let _ = tx_clone.send(AppMessage::Done);
             })
             .await
@@ -90,6 +291,251 @@ impl MyApp {
 
         self.search_results = Some((needle, results));
     }
+
+    /// Total size of every detected build-artifact directory.
+    fn reclaimable_bytes(&self) -> u64 {
+        self.targets.iter().map(|target| target.size_bytes).sum()
+    }
+
+    /// Sends the target at `index` to the OS trash on the blocking pool,
+    /// like `refresh_subdirs` does for crawling, so the UI stays responsive.
+    ///
+    /// The target stays in `self.targets` until a confirmed
+    /// `AppMessage::Trashed` comes back; if the trash op fails it's left in
+    /// place (and still reclaimable) instead of silently vanishing.
+    fn delete_target(&mut self, index: usize) {
+        let Some(target) = self.targets.get(index) else {
+            return;
+        };
+        let path = target.path.clone();
+        let size_bytes = target.size_bytes;
+        self.selected.remove(&path);
+        let tx_clone = self.tx.clone();
+        self.rt_handle.spawn(async move {
+            tokio::task::spawn_blocking(move || {
+                let msg = match trash::delete(&path) {
+                    Ok(()) => AppMessage::Trashed { path, size_bytes },
+                    Err(err) => AppMessage::TrashFailed {
+                        path,
+                        error: err.to_string(),
+                    },
+                };
+                let _ = tx_clone.send(msg);
+            })
+            .await
+            .ok();
+        });
+    }
+
+    /// Selects (or deselects) every detected target.
+    fn select_all(&mut self, select: bool) {
+        if select {
+            self.selected = self
+                .targets
+                .iter()
+                .map(|target| target.path.clone())
+                .collect();
+        } else {
+            self.selected.clear();
+        }
+    }
+
+    /// Adds every target older than `days` days to the selection.
+    fn select_older_than(&mut self, days: u32) {
+        let age = std::time::Duration::from_secs(u64::from(days) * 86_400);
+        let Some(threshold) = SystemTime::now().checked_sub(age) else {
+            return;
+        };
+        for target in &self.targets {
+            if target.last_modified <= threshold {
+                self.selected.insert(target.path.clone());
+            }
+        }
+    }
+
+    /// Adds every target larger than `mib` MiB to the selection.
+    fn select_larger_than(&mut self, mib: u64) {
+        let threshold_bytes = mib.saturating_mul(1024 * 1024);
+        for target in &self.targets {
+            if target.size_bytes >= threshold_bytes {
+                self.selected.insert(target.path.clone());
+            }
+        }
+    }
+
+    /// Total size of everything currently selected.
+    fn selected_bytes(&self) -> u64 {
+        self.targets
+            .iter()
+            .filter(|target| self.selected.contains(&target.path))
+            .map(|target| target.size_bytes)
+            .sum()
+    }
+
+    /// Trashes every selected target on the blocking pool, reporting
+    /// progress and a final summary through `AppMessage`.
+    ///
+    /// Targets stay in `self.targets` until each one's own confirmed
+    /// `AppMessage::Trashed` comes back, so a failure partway through the
+    /// batch (busy file, permission denied, ...) leaves that entry visible
+    /// and reclaimable instead of dropping it unconditionally up front.
+    fn clean_selected(&mut self) {
+        let selected = std::mem::take(&mut self.selected);
+        let to_clean: Vec<(PathBuf, u64)> = self
+            .targets
+            .iter()
+            .filter(|target| selected.contains(&target.path))
+            .map(|target| (target.path.clone(), target.size_bytes))
+            .collect();
+
+        if to_clean.is_empty() {
+            return;
+        }
+
+        let total = to_clean.len();
+        self.clean_progress = Some((0, total));
+        let tx_clone = self.tx.clone();
+        self.rt_handle.spawn(async move {
+            tokio::task::spawn_blocking(move || {
+                let mut total_freed = 0u64;
+                let mut cleaned = 0usize;
+                for (completed, (path, size_bytes)) in to_clean.into_iter().enumerate() {
+                    let msg = match trash::delete(&path) {
+                        Ok(()) => {
+                            total_freed += size_bytes;
+                            cleaned += 1;
+                            AppMessage::Trashed { path, size_bytes }
+                        }
+                        Err(err) => AppMessage::TrashFailed {
+                            path,
+                            error: err.to_string(),
+                        },
+                    };
+                    let _ = tx_clone.send(msg);
+                    let _ = tx_clone.send(AppMessage::CleanProgress {
+                        completed: completed + 1,
+                        total,
+                    });
+                }
+                let _ = tx_clone.send(AppMessage::CleanComplete {
+                    total_freed,
+                    count: cleaned,
+                });
+            })
+            .await
+            .ok();
+        });
+    }
+
+    /// Restores the most recently trashed directory.
+    fn restore_last(&mut self) {
+        let Some(entry) = self.recently_trashed.pop() else {
+            return;
+        };
+        let tx_clone = self.tx.clone();
+        self.rt_handle.spawn(async move {
+            tokio::task::spawn_blocking(move || {
+                let msg = restore_path(&entry.path)
+                    .map(|()| AppMessage::Restored {
+                        path: entry.path.clone(),
+                        size_bytes: entry.size_bytes,
+                    })
+                    .unwrap_or_else(|err| AppMessage::TrashFailed {
+                        path: entry.path,
+                        error: err,
+                    });
+                let _ = tx_clone.send(msg);
+            })
+            .await
+            .ok();
+        });
+    }
+
+    /// Turns live watching of `root` on or off.
+    fn set_watching(&mut self, root: PathBuf, enabled: bool) {
+        if !enabled {
+            self.watchers.remove(&root);
+            return;
+        }
+        if self.watchers.contains_key(&root) {
+            return;
+        }
+
+        let ignore_matcher = match crawler::build_ignore_matcher(&self.ignore_patterns) {
+            Ok(matcher) => Arc::new(matcher),
+            Err(err) => {
+                self.pattern_error = Some(format!("Failed to compile ignore patterns: {err}"));
+                return;
+            }
+        };
+
+        let pool = match self.watch_pool() {
+            Ok(pool) => pool,
+            Err(err) => {
+                self.trash_status = Some(format!("Failed to start worker pool: {err}"));
+                return;
+            }
+        };
+
+        match watcher::watch_root(root.clone(), self.tx.clone(), ignore_matcher, pool) {
+            Ok(root_watcher) => {
+                self.watchers.insert(root, root_watcher);
+            }
+            Err(err) => {
+                self.trash_status = Some(format!("Failed to watch {}: {}", root.display(), err));
+            }
+        }
+    }
+
+    /// Returns the worker pool shared by every watched root's re-measure
+    /// work, building it on first use instead of allocating a fresh pool
+    /// (sized to `thread_count`, all available cores by default) per root.
+    fn watch_pool(&mut self) -> std::io::Result<Arc<ThreadPool>> {
+        if let Some(pool) = &self.watch_pool {
+            return Ok(pool.clone());
+        }
+        let pool = crawler::build_worker_pool(Some(self.thread_count))?;
+        self.watch_pool = Some(pool.clone());
+        Ok(pool)
+    }
+
+    /// Draws a draggable vertical handle that resizes `column_widths[index]`
+    /// (the column to its left), so dragged widths land directly in state
+    /// that `to_config`/`save_config` already persist, rather than relying
+    /// on `egui_extras` to hand back widths it never exposes publicly.
+    fn render_column_splitter(&mut self, ui: &mut egui::Ui, index: usize) {
+        let size = egui::vec2(SPLITTER_WIDTH, ui.available_height());
+        let (rect, response) = ui.allocate_exact_size(size, egui::Sense::drag());
+
+        if response.dragged() {
+            self.column_widths[index] =
+                (self.column_widths[index] + response.drag_delta().x).max(MIN_COLUMN_WIDTH);
+        }
+
+        let stroke = if response.dragged() || response.hovered() {
+            ui.visuals().widgets.active.bg_stroke
+        } else {
+            ui.visuals().widgets.noninteractive.bg_stroke
+        };
+        ui.painter().vline(rect.center().x, rect.y_range(), stroke);
+
+        if response.hovered() || response.dragged() {
+            ui.ctx().set_cursor_icon(egui::CursorIcon::ResizeHorizontal);
+        }
+    }
+}
+
+/// Finds `path` among the OS trash's items and restores it.
+fn restore_path(path: &std::path::Path) -> Result<(), String> {
+    let items = trash::os_limited::list().map_err(|err| err.to_string())?;
+    let matching: Vec<_> = items
+        .into_iter()
+        .filter(|item| item.original_path() == path)
+        .collect();
+    if matching.is_empty() {
+        return Err("Could not find this path in the trash".to_owned());
+    }
+    trash::os_limited::restore_all(matching).map_err(|err| err.to_string())
 }
 
 impl eframe::App for MyApp {
@@ -106,34 +552,147 @@ impl eframe::App for MyApp {
                 AppMessage::Subdir(path) => {
                     self.subdirs.push(path);
                 }
+                AppMessage::CleanTarget {
+                    path,
+                    size_bytes,
+                    last_modified,
+                } => {
+                    self.targets.push(CleanTarget {
+                        path,
+                        size_bytes,
+                        last_modified,
+                    });
+                }
+                AppMessage::Trashed { path, size_bytes } => {
+                    self.targets.retain(|target| target.path != path);
+                    self.trash_status = Some(format!(
+                        "Freed {} by trashing {}",
+                        human_size(size_bytes),
+                        path.display()
+                    ));
+                    self.recently_trashed
+                        .push(TrashedEntry { path, size_bytes });
+                    if self.recently_trashed.len() > MAX_RECENTLY_TRASHED {
+                        self.recently_trashed.remove(0);
+                    }
+                }
+                AppMessage::Restored { path, size_bytes } => {
+                    self.trash_status = Some(format!(
+                        "Restored {} ({})",
+                        path.display(),
+                        human_size(size_bytes)
+                    ));
+                }
+                AppMessage::TrashFailed { path, error } => {
+                    self.trash_status = Some(format!("Failed for {}: {}", path.display(), error));
+                }
+                AppMessage::Removed(path) => {
+                    self.subdirs.retain(|subdir| subdir != &path);
+                    self.targets.retain(|target| target.path != path);
+                    self.selected.remove(&path);
+                }
+                AppMessage::Updated { path, size_bytes } => {
+                    match self.targets.iter_mut().find(|target| target.path == path) {
+                        Some(target) => {
+                            target.size_bytes = size_bytes;
+                            target.last_modified = SystemTime::now();
+                        }
+                        None => {
+                            self.targets.push(CleanTarget {
+                                path,
+                                size_bytes,
+                                last_modified: SystemTime::now(),
+                            });
+                        }
+                    }
+                }
+                AppMessage::CleanProgress { completed, total } => {
+                    self.clean_progress = if completed >= total {
+                        None
+                    } else {
+                        Some((completed, total))
+                    };
+                }
+                AppMessage::CleanComplete { total_freed, count } => {
+                    self.clean_progress = None;
+                    self.clean_summary = Some(format!(
+                        "Cleaned {count} director{} (freed {})",
+                        if count == 1 { "y" } else { "ies" },
+                        human_size(total_freed)
+                    ));
+                }
                 AppMessage::Done => {
                     self.indexing_in_progress = false;
                 }
             }
         }
 
+        let screen_rect = ctx.input(|i| i.screen_rect());
+        self.window_width = screen_rect.width();
+        self.window_height = screen_rect.height();
+
+        if self.show_clean_confirm {
+            let (count, bytes) = (self.selected.len(), self.selected_bytes());
+            let mut confirmed = false;
+            let mut cancelled = false;
+            egui::Window::new("Confirm clean")
+                .collapsible(false)
+                .resizable(false)
+                .show(ctx, |ui| {
+                    ui.label(format!(
+                        "This will send {count} director{} ({}) to the trash.",
+                        if count == 1 { "y" } else { "ies" },
+                        human_size(bytes)
+                    ));
+                    ui.horizontal(|ui| {
+                        if ui.button("Clean selected").clicked() {
+                            confirmed = true;
+                        }
+                        if ui.button("Cancel").clicked() {
+                            cancelled = true;
+                        }
+                    });
+                });
+            if confirmed {
+                self.show_clean_confirm = false;
+                self.clean_selected();
+            } else if cancelled {
+                self.show_clean_confirm = false;
+            }
+        }
+
         egui::CentralPanel::default().show(ctx, |ui| {
             ui.heading("Cargo Clean Helper");
+            ui.label(format!(
+                "{} reclaimable across {} target(s)",
+                human_size(self.reclaimable_bytes()),
+                self.targets.len()
+            ));
             ui.separator();
 
             let height = ui.available_height();
             TableBuilder::new(ui)
-                .resizable(true)
                 .striped(true)
-                .column(Column::remainder().at_least(150.0)) // "Roots"
-                .column(Column::remainder().at_least(150.0)) // "Subdirs"
-                .column(Column::remainder().at_least(150.0)) // "Search"
-                .column(Column::remainder().at_least(150.0)) // "Ignore Patterns"
+                .column(Column::exact(self.column_widths[0]).at_least(MIN_COLUMN_WIDTH)) // "Roots"
+                .column(Column::exact(SPLITTER_WIDTH))
+                .column(Column::exact(self.column_widths[1]).at_least(MIN_COLUMN_WIDTH)) // "Ignore Patterns"
+                .column(Column::exact(SPLITTER_WIDTH))
+                .column(Column::exact(self.column_widths[2]).at_least(MIN_COLUMN_WIDTH)) // "Targets"
+                .column(Column::exact(SPLITTER_WIDTH))
+                .column(Column::remainder().at_least(MIN_COLUMN_WIDTH)) // "Search"
                 .header(20.0, |mut header| {
                     header.col(|ui| {
                         ui.strong("Roots");
                     });
+                    header.col(|_ui| {});
                     header.col(|ui| {
                         ui.strong("Ignore Patterns");
                     });
+                    header.col(|_ui| {});
                     header.col(|ui| {
-                        ui.strong("Subdirs");
+                        ui.strong("Targets");
                     });
+                    header.col(|_ui| {});
                     header.col(|ui| {
                         ui.strong("Search");
                     });
@@ -144,15 +703,45 @@ impl eframe::App for MyApp {
                         // --- Roots Column ---
                         row.col(|ui| {
                             ui.label(format!("Roots ({} entries)", self.root_dirs.len()));
+
+                            ui.horizontal(|ui| {
+                                ui.text_edit_singleline(&mut self.new_root_input);
+                                if ui.button("Add root").clicked() {
+                                    let root = self.new_root_input.trim();
+                                    if !root.is_empty() {
+                                        self.root_dirs.push(PathBuf::from(root));
+                                        self.new_root_input.clear();
+                                        self.save_config();
+                                    }
+                                }
+                            });
+
+                            let mut watch_toggle = None;
+                            let mut remove_root_index = None;
                             egui::ScrollArea::vertical()
                                 .max_height(200.0)
                                 .show(ui, |ui| {
-                                    for path in self.root_dirs.iter() {
+                                    for (i, path) in self.root_dirs.iter().enumerate() {
                                         ui.horizontal(|ui| {
                                             ui.label(path.display().to_string());
+                                            let mut watching = self.watchers.contains_key(path);
+                                            if ui.checkbox(&mut watching, "Watch").changed() {
+                                                watch_toggle = Some((path.clone(), watching));
+                                            }
+                                            if ui.button("Remove").clicked() {
+                                                remove_root_index = Some(i);
+                                            }
                                         });
                                     }
                                 });
+                            if let Some((root, enabled)) = watch_toggle {
+                                self.set_watching(root, enabled);
+                            }
+                            if let Some(i) = remove_root_index {
+                                let removed = self.root_dirs.remove(i);
+                                self.watchers.remove(&removed);
+                                self.save_config();
+                            }
 
                             if ui.button("Copy to clipboard").clicked() {
                                 ui.ctx().copy_text(
@@ -163,6 +752,11 @@ impl eframe::App for MyApp {
                                 );
                             }
 
+                            ui.horizontal(|ui| {
+                                ui.label("Crawl threads:");
+                                ui.add(egui::DragValue::new(&mut self.thread_count).range(1..=64));
+                            });
+
                             let refresh_btn = ui.add_enabled(
                                 !self.indexing_in_progress,
                                 egui::Button::new("Refresh subdirs"),
@@ -176,19 +770,36 @@ impl eframe::App for MyApp {
                             }
                         });
 
+                        row.col(|ui| {
+                            self.render_column_splitter(ui, 0);
+                        });
+
                         // --- Ignore Patterns Column ---
                         row.col(|ui| {
-                            ui.label("Add new ignore pattern:");
+                            ui.label("Add new ignore pattern (glob, e.g. **/target):");
                             ui.horizontal(|ui| {
                                 ui.text_edit_singleline(&mut self.new_pattern_input);
                                 if ui.button("Add").clicked() {
-                                    if !self.new_pattern_input.trim().is_empty() {
-                                        self.ignore_patterns
-                                            .push(self.new_pattern_input.trim().to_string());
-                                        self.new_pattern_input.clear();
+                                    let pattern = self.new_pattern_input.trim().to_string();
+                                    if !pattern.is_empty() {
+                                        match globset::Glob::new(&pattern) {
+                                            Ok(_) => {
+                                                self.ignore_patterns.push(pattern);
+                                                self.new_pattern_input.clear();
+                                                self.pattern_error = None;
+                                                self.save_config();
+                                            }
+                                            Err(err) => {
+                                                self.pattern_error =
+                                                    Some(format!("Invalid pattern: {err}"));
+                                            }
+                                        }
                                     }
                                 }
                             });
+                            if let Some(error) = &self.pattern_error {
+                                ui.colored_label(egui::Color32::RED, error);
+                            }
 
                             ui.separator();
                             ui.label("Current ignore patterns:");
@@ -207,46 +818,131 @@ impl eframe::App for MyApp {
                             }
                             if let Some(i) = remove_index {
                                 self.ignore_patterns.remove(i);
+                                self.save_config();
                             }
                         });
 
-                        // --- Subdirs Column ---
-                        // --- Subdirs Column ---
+                        row.col(|ui| {
+                            self.render_column_splitter(ui, 1);
+                        });
+
+                        // --- Targets Column ---
                         row.col(|ui| {
                             ui.vertical(|ui| {
-                                ui.label(format!("Subdirs ({} entries)", self.subdirs.len()));
+                                ui.label(format!(
+                                    "Targets ({} entries, {} reclaimable, {} selected)",
+                                    self.targets.len(),
+                                    human_size(self.reclaimable_bytes()),
+                                    self.selected.len()
+                                ));
+
+                                ui.horizontal(|ui| {
+                                    if ui.button("Select all").clicked() {
+                                        self.select_all(true);
+                                    }
+                                    if ui.button("Select none").clicked() {
+                                        self.select_all(false);
+                                    }
+                                });
+                                ui.horizontal(|ui| {
+                                    ui.add(
+                                        egui::DragValue::new(&mut self.select_older_than_days)
+                                            .suffix(" days"),
+                                    );
+                                    if ui.button("Select older than").clicked() {
+                                        self.select_older_than(self.select_older_than_days);
+                                    }
+                                });
+                                ui.horizontal(|ui| {
+                                    ui.add(
+                                        egui::DragValue::new(&mut self.select_larger_than_mib)
+                                            .suffix(" MiB"),
+                                    );
+                                    if ui.button("Select larger than").clicked() {
+                                        self.select_larger_than(self.select_larger_than_mib);
+                                    }
+                                });
 
                                 // The scroll area should take most of the available space
                                 let available_height = ui.available_height() - 40.0; // Reserve space for button and spacing
 
+                                let mut to_delete = None;
                                 egui::ScrollArea::vertical()
                                     .auto_shrink([false, false])
                                     .max_height(available_height)
                                     .show(ui, |ui| {
-                                        for subdir in &self.subdirs {
+                                        for (i, target) in self.targets.iter().enumerate() {
                                             ui.horizontal(|ui| {
-                                                ui.label(subdir.display().to_string());
+                                                let mut checked =
+                                                    self.selected.contains(&target.path);
+                                                if ui.checkbox(&mut checked, "").changed() {
+                                                    if checked {
+                                                        self.selected.insert(target.path.clone());
+                                                    } else {
+                                                        self.selected.remove(&target.path);
+                                                    }
+                                                }
+                                                ui.label(target.path.display().to_string());
+                                                ui.label(human_size(target.size_bytes));
+                                                ui.label(format_age(target.last_modified));
+                                                if ui.button("Trash").clicked() {
+                                                    to_delete = Some(i);
+                                                }
                                             });
                                         }
                                     });
+                                if let Some(i) = to_delete {
+                                    self.delete_target(i);
+                                }
 
                                 // Add some spacing before the button
                                 ui.add_space(5.0);
 
-                                // Center the button horizontally
+                                // Center the buttons horizontally
                                 ui.with_layout(egui::Layout::top_down(egui::Align::Center), |ui| {
                                     if ui.button("Copy to clipboard").clicked() {
                                         ui.ctx().copy_text(
-                                            self.subdirs
+                                            self.targets
                                                 .iter()
-                                                .map(|x| x.display().to_string())
+                                                .map(|x| x.path.display().to_string())
                                                 .join("\n"),
                                         );
                                     }
+                                    if ui
+                                        .add_enabled(
+                                            !self.recently_trashed.is_empty(),
+                                            egui::Button::new("Restore last"),
+                                        )
+                                        .clicked()
+                                    {
+                                        self.restore_last();
+                                    }
+                                    if ui
+                                        .add_enabled(
+                                            !self.selected.is_empty(),
+                                            egui::Button::new("Clean selected"),
+                                        )
+                                        .clicked()
+                                    {
+                                        self.show_clean_confirm = true;
+                                    }
+                                    if let Some((completed, total)) = self.clean_progress {
+                                        ui.label(format!("Cleaning {completed}/{total}..."));
+                                    }
+                                    if let Some(summary) = &self.clean_summary {
+                                        ui.label(summary);
+                                    }
+                                    if let Some(status) = &self.trash_status {
+                                        ui.label(status);
+                                    }
                                 });
                             });
                         });
 
+                        row.col(|ui| {
+                            self.render_column_splitter(ui, 2);
+                        });
+
                         // --- Search Column ---
                         row.col(|ui| {
                             ui.label("Search:");
@@ -284,4 +980,8 @@ impl eframe::App for MyApp {
                 });
         });
     }
+
+    fn on_exit(&mut self) {
+        self.save_config();
+    }
 }
@@ -0,0 +1,72 @@
+// src/config.rs
+
+use serde::Deserialize;
+use serde::Serialize;
+use std::path::PathBuf;
+use tracing::warn;
+
+/// Persisted user settings: search roots, ignore patterns, and window
+/// layout, so the app remembers how it was last set up between launches.
+#[derive(Serialize, Deserialize, Clone)]
+#[serde(default)]
+pub struct Config {
+    pub root_dirs: Vec<PathBuf>,
+    pub ignore_patterns: Vec<String>,
+    pub window_width: f32,
+    pub window_height: f32,
+    /// Widths for the Roots / Ignore Patterns / Targets / Search columns.
+    /// The Search column fills the remaining space, so the fourth width is
+    /// stored but not applied or updated.
+    pub column_widths: [f32; 4],
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            root_dirs: Vec::new(),
+            ignore_patterns: vec![".git".to_owned()],
+            window_width: 920.0,
+            window_height: 550.0,
+            column_widths: [150.0, 150.0, 150.0, 150.0],
+        }
+    }
+}
+
+impl Config {
+    /// Loads the config from the platform config directory, falling back to
+    /// [`Config::default`] when it's missing or fails to parse.
+    pub fn load() -> Self {
+        let Some(path) = Self::path() else {
+            return Self::default();
+        };
+
+        let Ok(contents) = std::fs::read_to_string(&path) else {
+            return Self::default();
+        };
+
+        toml::from_str(&contents).unwrap_or_else(|err| {
+            warn!(
+                "Failed to parse config at {:?}, using defaults: {}",
+                path, err
+            );
+            Self::default()
+        })
+    }
+
+    /// Writes the config to the platform config directory, creating it if needed.
+    pub fn save(&self) -> eyre::Result<()> {
+        let path =
+            Self::path().ok_or_else(|| eyre::eyre!("Could not determine config directory"))?;
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        let contents = toml::to_string_pretty(self)?;
+        std::fs::write(&path, contents)?;
+        Ok(())
+    }
+
+    fn path() -> Option<PathBuf> {
+        directories::ProjectDirs::from("dev", "TeamDman", "cargo-clean-helper")
+            .map(|dirs| dirs.config_dir().join("config.toml"))
+    }
+}
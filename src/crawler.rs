@@ -1,52 +1,261 @@
 // src/crawler.rs
 
+use globset::Glob;
+use globset::GlobSet;
+use globset::GlobSetBuilder;
+use jwalk::Parallelism;
+use jwalk::WalkDir;
+use rayon::prelude::*;
+use rayon::ThreadPool;
+use std::path::Path;
 use std::path::PathBuf;
 use std::sync::mpsc::Sender;
+use std::sync::Arc;
+use std::time::SystemTime;
 use tracing::info;
-use walkdir::DirEntry;
-use walkdir::WalkDir;
 
 use crate::app::AppMessage;
 
-/// Filter function that returns `false` if the path should be skipped.
-fn filter_entry(entry: &DirEntry, ignore_list: &[String]) -> bool {
-    let path_str = entry.path().display().to_string();
+/// Compiles the user's ignore patterns (gitignore/glob-style: `*`, `**`, `?`,
+/// anchored or unanchored) into a single [`GlobSet`], once per crawl.
+///
+/// Returns the first compile error so the caller can surface *which* pattern
+/// is broken rather than silently dropping it.
+pub fn build_ignore_matcher(patterns: &[String]) -> Result<GlobSet, globset::Error> {
+    let mut builder = GlobSetBuilder::new();
+    for pattern in patterns {
+        builder.add(Glob::new(&unanchor(pattern))?);
+    }
+    builder.build()
+}
+
+/// Gives a pattern gitignore-style "match at any depth" semantics: a pattern
+/// with no path separator (e.g. `.git`) is meant to match that name
+/// anywhere under the crawl root, not just a top-level entry, so it's
+/// rewritten as `**/<pattern>`. A pattern that already contains a `/` is
+/// left alone, since the user has anchored it themselves.
+fn unanchor(pattern: &str) -> String {
+    if pattern.contains('/') {
+        pattern.to_owned()
+    } else {
+        format!("**/{pattern}")
+    }
+}
+
+/// Builds a worker pool sized to `thread_count`, or the machine's available
+/// parallelism when `None`. Shared (via the returned `Arc`) across every
+/// root and subtree in a crawl, so a directory tree spread over several
+/// drives scales with the machine instead of walking root after root.
+pub fn build_worker_pool(thread_count: Option<usize>) -> std::io::Result<Arc<ThreadPool>> {
+    let num_threads = thread_count.unwrap_or_else(|| {
+        std::thread::available_parallelism()
+            .map(std::num::NonZeroUsize::get)
+            .unwrap_or(1)
+    });
 
-    // If any ignore pattern is found in the path, skip:
-    !ignore_list.iter().any(|pattern| path_str.contains(pattern))
+    rayon::ThreadPoolBuilder::new()
+        .num_threads(num_threads)
+        .build()
+        .map(Arc::new)
+        .map_err(std::io::Error::other)
 }
 
-/// Collects *all* descendant directories from a root, sending them line-by-line,
-/// but skipping any path containing an ignore pattern.
-pub fn gather_descendant_dirs_streaming(
+/// Returns `true` if `path` is a `target/` directory produced by Cargo, i.e.
+/// it is literally named `target` and sits next to a `Cargo.toml`.
+pub(crate) fn is_cargo_target_path(path: &Path) -> bool {
+    path.file_name().is_some_and(|name| name == "target")
+        && path
+            .parent()
+            .is_some_and(|parent| parent.join("Cargo.toml").is_file())
+}
+
+/// Walks `target_dir` and sums the size of every contained file, along with
+/// the newest modification time seen. Used to report reclaimable space and
+/// staleness for a detected build-artifact directory.
+///
+/// Runs on `pool` so measuring a large `target/` (lots of cached crates)
+/// doesn't become the serial bottleneck of an otherwise parallel crawl.
+pub(crate) fn measure_target_dir(target_dir: &Path, pool: &Arc<ThreadPool>) -> (u64, SystemTime) {
+    let mut size_bytes = 0u64;
+    let mut last_modified = SystemTime::UNIX_EPOCH;
+
+    let walker = WalkDir::new(target_dir)
+        .parallelism(Parallelism::RayonExistingPool {
+            pool: pool.clone(),
+            busy_timeout: None,
+        })
+        .into_iter();
+
+    for entry in walker.filter_map(Result::ok) {
+        if let Ok(metadata) = entry.metadata() {
+            if metadata.is_file() {
+                size_bytes += metadata.len();
+                if let Ok(modified) = metadata.modified() {
+                    last_modified = last_modified.max(modified);
+                }
+            }
+        }
+    }
+
+    (size_bytes, last_modified)
+}
+
+/// Collects descendant directories from a root, sending them line-by-line,
+/// but skipping any path matching an ignore pattern. Rust build-artifact
+/// directories (a `target/` next to a `Cargo.toml`) are reported as
+/// [`AppMessage::CleanTarget`] instead of [`AppMessage::Subdir`], and are not
+/// recursed into.
+///
+/// The directory read itself is parallelized across `pool`, so large
+/// subtrees within a single root scale across cores too.
+fn gather_descendant_dirs_streaming(
     root_path: PathBuf,
     tx: &Sender<AppMessage>,
-    ignore_list: &[String],
+    ignore_matcher: &Arc<GlobSet>,
+    pool: &Arc<ThreadPool>,
 ) {
     info!(
         "Starting to gather descendant directories from: {:?}",
         root_path
     );
-    // Use .filter_entry() to prune directories we want to ignore
+
+    let root_for_filter = root_path.clone();
+    let ignore_matcher_for_filter = ignore_matcher.clone();
     let walker = WalkDir::new(&root_path)
-        .follow_links(true)
-        .into_iter()
-        .filter_entry(|e| filter_entry(e, ignore_list));
-
-    for entry_result in walker {
-        match entry_result {
-            Ok(entry) if entry.file_type().is_dir() => {
-                // If the receiver side is closed, break
-                if tx
-                    .send(AppMessage::Subdir(entry.path().to_path_buf()))
-                    .is_err()
-                {
-                    break;
+        .parallelism(Parallelism::RayonExistingPool {
+            pool: pool.clone(),
+            busy_timeout: None,
+        })
+        .process_read_dir(move |_depth, _parent, _read_dir_state, children| {
+            // Prune ignored entries before jwalk recurses into them...
+            children.retain(|entry_result| match entry_result {
+                Ok(entry) => {
+                    let relative = entry
+                        .path()
+                        .strip_prefix(&root_for_filter)
+                        .map(Path::to_path_buf)
+                        .unwrap_or_else(|_| entry.path());
+                    !ignore_matcher_for_filter.is_match(relative)
                 }
+                Err(_) => true,
+            });
+
+            // ...and stop jwalk from descending into a detected target/ dir,
+            // since we measure it ourselves in one dedicated pass below.
+            for entry_result in children.iter_mut().flatten() {
+                if entry_result.file_type().is_dir() && is_cargo_target_path(&entry_result.path()) {
+                    entry_result.read_children_path = None;
+                }
+            }
+        });
+
+    for entry in walker.into_iter().filter_map(Result::ok) {
+        if !entry.file_type().is_dir() {
+            continue;
+        }
+
+        if is_cargo_target_path(&entry.path()) {
+            let (size_bytes, last_modified) = measure_target_dir(&entry.path(), pool);
+            if tx
+                .send(AppMessage::CleanTarget {
+                    path: entry.path(),
+                    size_bytes,
+                    last_modified,
+                })
+                .is_err()
+            {
+                // Dropping `walker`'s iterator here tells jwalk to stop
+                // feeding it more work, so the worker pool winds down
+                // promptly instead of finishing an abandoned crawl.
+                return;
             }
-            // We ignore files, but you could also track them if needed
-            _ => {}
+            continue;
+        }
+
+        if tx.send(AppMessage::Subdir(entry.path())).is_err() {
+            return;
         }
     }
+
     info!("Finished gathering directories from: {:?}", root_path);
 }
+
+/// Crawls every root in `roots` concurrently, fanning out across `pool`.
+/// Each root (and each subtree within it) shares the same bounded worker
+/// pool and the same compiled ignore matcher, so the cost of a large
+/// collection of repos scales with available parallelism rather than the
+/// number of roots.
+pub fn crawl_roots_parallel(
+    roots: Vec<PathBuf>,
+    tx: Sender<AppMessage>,
+    ignore_matcher: Arc<GlobSet>,
+    pool: Arc<ThreadPool>,
+) {
+    // Clone the sender once per root up front, since `mpsc::Sender` isn't
+    // `Sync` and so can't be called from multiple rayon workers through a
+    // shared reference.
+    let jobs: Vec<(PathBuf, Sender<AppMessage>)> =
+        roots.into_iter().map(|root| (root, tx.clone())).collect();
+
+    pool.install(|| {
+        jobs.into_par_iter().for_each(|(root, tx)| {
+            gather_descendant_dirs_streaming(root, &tx, &ignore_matcher, &pool);
+        });
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn unanchor_leaves_separator_containing_patterns_alone() {
+        assert_eq!(unanchor("target"), "**/target");
+        assert_eq!(unanchor(".git"), "**/.git");
+        assert_eq!(unanchor("**/already-anchored"), "**/already-anchored");
+        assert_eq!(unanchor("src/target"), "src/target");
+    }
+
+    #[test]
+    fn build_ignore_matcher_matches_pattern_at_any_depth() {
+        let matcher = build_ignore_matcher(&[".git".to_owned()]).unwrap();
+
+        assert!(matcher.is_match(Path::new(".git")));
+        assert!(matcher.is_match(Path::new("project1/.git")));
+        assert!(matcher.is_match(Path::new("a/b/c/.git")));
+        // Must not substring-match a similarly-named but distinct directory.
+        assert!(!matcher.is_match(Path::new(".github")));
+        assert!(!matcher.is_match(Path::new("project1/.github")));
+    }
+
+    #[test]
+    fn build_ignore_matcher_surfaces_invalid_glob() {
+        assert!(build_ignore_matcher(&["[".to_owned()]).is_err());
+    }
+
+    #[test]
+    fn is_cargo_target_path_requires_sibling_cargo_toml() {
+        let dir = std::env::temp_dir().join(format!(
+            "cargo-clean-helper-test-{}-{:?}",
+            std::process::id(),
+            std::thread::current().id()
+        ));
+        std::fs::create_dir_all(&dir).unwrap();
+
+        let target_with_manifest = dir.join("target");
+        std::fs::create_dir_all(&target_with_manifest).unwrap();
+        std::fs::write(dir.join("Cargo.toml"), "").unwrap();
+        assert!(is_cargo_target_path(&target_with_manifest));
+
+        let other_dir = dir.join("not_a_target");
+        std::fs::create_dir_all(&other_dir).unwrap();
+        assert!(!is_cargo_target_path(&other_dir));
+
+        let orphan_dir = dir.join("no_manifest");
+        let orphan_target = orphan_dir.join("target");
+        std::fs::create_dir_all(&orphan_target).unwrap();
+        assert!(!is_cargo_target_path(&orphan_target));
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
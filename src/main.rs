@@ -1,10 +1,13 @@
 // src/main.rs
 
 mod app;
+mod config;
 mod crawler;
 mod init;
+mod watcher;
 
 use app::MyApp;
+use config::Config;
 use eframe::egui;
 use eyre::Result;
 use std::time::Duration;
@@ -13,6 +16,10 @@ use tokio::runtime::Runtime;
 fn main() -> Result<()> {
     init::init()?;
 
+    // Load persisted roots/ignore patterns/window layout before anything
+    // else, since the window size has to be known before eframe launches.
+    let config = Config::load();
+
     // 1) Create a Tokio runtime
     let rt = Runtime::new()?;
 
@@ -30,13 +37,12 @@ fn main() -> Result<()> {
     });
 
     // 3) Pass the runtime HANDLE (not the entire runtime) into our MyApp.
-    let app = MyApp::new(rt.handle().clone());
-
-    // 4) Launch eframe:
     let native_options = eframe::NativeOptions {
-        viewport: egui::ViewportBuilder::default().with_inner_size([920.0, 550.0]),
+        viewport: egui::ViewportBuilder::default()
+            .with_inner_size([config.window_width, config.window_height]),
         ..Default::default()
     };
+    let app = MyApp::new(rt.handle().clone(), config);
 
     eframe::run_native(
         "Cargo Clean Helper",
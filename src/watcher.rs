@@ -0,0 +1,128 @@
+// src/watcher.rs
+
+use crate::app::AppMessage;
+use crate::crawler;
+use globset::GlobSet;
+use notify::RecursiveMode;
+use notify::Watcher;
+use notify_debouncer_mini::new_debouncer;
+use notify_debouncer_mini::DebounceEventResult;
+use notify_debouncer_mini::DebouncedEventKind;
+use notify_debouncer_mini::Debouncer;
+use rayon::ThreadPool;
+use std::collections::HashSet;
+use std::path::Path;
+use std::path::PathBuf;
+use std::sync::mpsc::Sender;
+use std::sync::Arc;
+use std::time::Duration;
+use tracing::warn;
+
+/// How long to coalesce bursts of filesystem events before reporting them,
+/// so a `cargo build` doesn't thrash the UI with one message per file.
+const DEBOUNCE_WINDOW: Duration = Duration::from_millis(500);
+
+/// A live watch on a single root directory. Dropping this stops the watch.
+pub type RootWatcher = Debouncer<notify::RecommendedWatcher>;
+
+/// Starts watching `root` for filesystem changes, pushing incremental
+/// [`AppMessage`]s through `tx` as directories are created or removed, or as
+/// a detected `target/` directory's contents grow or shrink. The returned
+/// watcher must be kept alive for as long as `root` should stay watched.
+pub fn watch_root(
+    root: PathBuf,
+    tx: Sender<AppMessage>,
+    ignore_matcher: Arc<GlobSet>,
+    pool: Arc<ThreadPool>,
+) -> notify::Result<RootWatcher> {
+    let watched_root = root.clone();
+
+    let mut debouncer = new_debouncer(DEBOUNCE_WINDOW, move |result: DebounceEventResult| {
+        let events = match result {
+            Ok(events) => events,
+            Err(err) => {
+                warn!("Watch error for {:?}: {:?}", watched_root, err);
+                return;
+            }
+        };
+
+        dispatch_events(events, &watched_root, &tx, &ignore_matcher, &pool);
+    })?;
+
+    debouncer.watcher().watch(&root, RecursiveMode::Recursive)?;
+    Ok(debouncer)
+}
+
+/// Translates a whole debounced batch of filesystem changes into
+/// `AppMessage`s, skipping anything caught by the ignore patterns.
+///
+/// Events are grouped by their containing `target/` directory first, so a
+/// `cargo build` touching hundreds of distinct paths under the same
+/// `target/` within one debounce window still triggers a single re-walk of
+/// that directory instead of one per touched path.
+fn dispatch_events(
+    events: Vec<notify_debouncer_mini::DebouncedEvent>,
+    root: &Path,
+    tx: &Sender<AppMessage>,
+    ignore_matcher: &GlobSet,
+    pool: &Arc<ThreadPool>,
+) {
+    let mut dirty_targets = HashSet::new();
+    let mut removed_targets = HashSet::new();
+
+    for event in events {
+        if event.kind == DebouncedEventKind::AnyContinuous {
+            continue;
+        }
+        let path = event.path;
+        let relative = path.strip_prefix(root).unwrap_or(&path);
+        if ignore_matcher.is_match(relative) {
+            continue;
+        }
+
+        if let Some(target_dir) = find_containing_target(&path, root) {
+            if target_dir.is_dir() {
+                dirty_targets.insert(target_dir);
+            } else {
+                removed_targets.insert(target_dir);
+            }
+            continue;
+        }
+
+        if path.is_dir() {
+            let _ = tx.send(AppMessage::Subdir(path));
+        } else if !path.exists() {
+            let _ = tx.send(AppMessage::Removed(path));
+        }
+    }
+
+    for target_dir in removed_targets {
+        dirty_targets.remove(&target_dir);
+        let _ = tx.send(AppMessage::Removed(target_dir));
+    }
+
+    for target_dir in dirty_targets {
+        let (size_bytes, _last_modified) = crawler::measure_target_dir(&target_dir, pool);
+        let _ = tx.send(AppMessage::Updated {
+            path: target_dir,
+            size_bytes,
+        });
+    }
+}
+
+/// Walks up from `path` (no further than `root`) looking for an ancestor
+/// `target/` directory next to a `Cargo.toml`, so a change deep inside
+/// `target/debug/...` is reported as a change to the `target/` dir itself.
+fn find_containing_target(path: &Path, root: &Path) -> Option<PathBuf> {
+    let mut current = Some(path);
+    while let Some(candidate) = current {
+        if crawler::is_cargo_target_path(candidate) {
+            return Some(candidate.to_path_buf());
+        }
+        if candidate == root {
+            break;
+        }
+        current = candidate.parent();
+    }
+    None
+}